@@ -0,0 +1,103 @@
+use std::borrow::Cow;
+use std::str;
+
+/// A single fragment of a [`Path`]'s lossy UTF-8 rendering, yielded by
+/// [`Utf8LossyChunks`].
+///
+/// `valid` holds a run of well-formed UTF-8; `broken` is `true` when that run
+/// was cut short by an invalid byte sequence, which is rendered as a single
+/// U+FFFD replacement character. Concatenating every chunk's `valid` piece,
+/// inserting `"\u{FFFD}"` wherever `broken` is `true`, reproduces exactly what
+/// [`Path::to_string_lossy`] would produce.
+///
+/// [`Path`]: struct.Path.html
+/// [`Utf8LossyChunks`]: struct.Utf8LossyChunks.html
+/// [`Path::to_string_lossy`]: https://doc.rust-lang.org/std/path/struct.Path.html#method.to_string_lossy
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Utf8LossyChunk<'a> {
+    /// A valid UTF-8 fragment.
+    pub valid: Cow<'a, str>,
+    /// Whether a U+FFFD replacement character follows `valid`.
+    pub broken: bool,
+}
+
+/// An iterator over the lossy UTF-8 fragments of a path, created by
+/// [`Path::utf8_chunks`].
+///
+/// On Unix, this splits the path's raw bytes at each invalid UTF-8 sequence,
+/// borrowing every fragment from the path with no extra allocation. On other
+/// platforms, where there is no portable way to inspect the path's raw
+/// encoding, it falls back to yielding the path's [`to_string_lossy`]
+/// rendering as a single chunk.
+///
+/// [`Path::utf8_chunks`]: struct.Path.html#method.utf8_chunks
+/// [`to_string_lossy`]: https://doc.rust-lang.org/std/path/struct.Path.html#method.to_string_lossy
+#[derive(Debug, Clone)]
+pub struct Utf8LossyChunks<'a> {
+    state: State<'a>,
+}
+
+#[derive(Debug, Clone)]
+enum State<'a> {
+    Bytes(&'a [u8]),
+    Lossy(Option<Cow<'a, str>>),
+}
+
+impl<'a> Utf8LossyChunks<'a> {
+    /// Splits `bytes` at each invalid UTF-8 sequence, borrowing every
+    /// fragment. Used on platforms where a path's raw encoding is available.
+    pub(crate) fn from_bytes(bytes: &'a [u8]) -> Self {
+        Utf8LossyChunks {
+            state: State::Bytes(bytes),
+        }
+    }
+
+    /// Wraps an already lossily-converted string as a single chunk. Used on
+    /// platforms with no portable access to a path's raw encoding.
+    pub(crate) fn from_lossy(text: Cow<'a, str>) -> Self {
+        Utf8LossyChunks {
+            state: State::Lossy(Some(text)),
+        }
+    }
+}
+
+impl<'a> Iterator for Utf8LossyChunks<'a> {
+    type Item = Utf8LossyChunk<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.state {
+            State::Bytes(bytes) => {
+                if bytes.is_empty() {
+                    return None;
+                }
+
+                match str::from_utf8(bytes) {
+                    Ok(valid) => {
+                        *bytes = &bytes[..0];
+                        Some(Utf8LossyChunk {
+                            valid: Cow::Borrowed(valid),
+                            broken: false,
+                        })
+                    }
+                    Err(error) => {
+                        let valid_len = error.valid_up_to();
+                        // Safety: `str::from_utf8` just confirmed this prefix is valid.
+                        let valid = unsafe { str::from_utf8_unchecked(&bytes[..valid_len]) };
+
+                        let invalid_len = error.error_len().unwrap_or(bytes.len() - valid_len);
+                        *bytes = &bytes[valid_len + invalid_len..];
+
+                        Some(Utf8LossyChunk {
+                            valid: Cow::Borrowed(valid),
+                            broken: true,
+                        })
+                    }
+                }
+            }
+            State::Lossy(text) => text.take().map(|valid| Utf8LossyChunk {
+                valid,
+                broken: false,
+            }),
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use std::ffi::OsStr;
 
+use crate::path::lossy::Utf8LossyChunks;
 use crate::path::{Ancestors, Components, Display, PathBuf};
 use crate::{fs, io};
 
@@ -144,6 +145,48 @@ impl Path {
         self.inner.ends_with(child)
     }
 
+    /// Determines whether `child` is a suffix of `self`, ignoring ASCII case.
+    ///
+    /// Only considers whole path components to match, comparing each [`Normal`]
+    /// component with an ASCII case-insensitive equality and every other
+    /// component (`RootDir`, `Prefix`, `CurDir`, `ParentDir`) exactly, just like
+    /// [`ends_with`].
+    ///
+    /// This is useful on filesystems that are case-insensitive by default, such
+    /// as NTFS, APFS, or FAT, where [`ends_with`] is too strict because it is
+    /// always case-sensitive regardless of platform.
+    ///
+    /// [`Normal`]: enum.Component.html#variant.Normal
+    /// [`ends_with`]: struct.Path.html#method.ends_with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use async_std::path::Path;
+    ///
+    /// let path = Path::new("/etc/Passwd");
+    ///
+    /// assert!(path.ends_with_ignore_case("passwd"));
+    /// ```
+    pub fn ends_with_ignore_case<P: AsRef<Path>>(&self, child: P) -> bool {
+        let child = child.as_ref();
+        let mut self_components = self.inner.components().rev();
+        let child_components = child.inner.components().rev();
+
+        for child_component in child_components {
+            match self_components.next() {
+                Some(self_component) => {
+                    if !components_eq_ignore_case(self_component, child_component) {
+                        return false;
+                    }
+                }
+                None => return false,
+            }
+        }
+
+        true
+    }
+
     /// Returns `true` if the path points at an existing entity.
     ///
     /// This function will traverse symbolic links to query information about the
@@ -209,6 +252,76 @@ impl Path {
         inner.into_path_buf().into()
     }
 
+    /// Returns `true` if the path exists on disk and is pointing at a directory.
+    ///
+    /// This function will traverse symbolic links to query information about the
+    /// destination file. In case of broken symbolic links this will return `false`.
+    ///
+    /// If you cannot access the directory containing the file, e.g., because of a
+    /// permission error, this will return `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> std::io::Result<()> { async_std::task::block_on(async {
+    /// #
+    /// use async_std::path::Path;
+    /// assert_eq!(Path::new("./is_a_directory/").is_dir().await, true);
+    /// assert_eq!(Path::new("a_file.txt").is_dir().await, false);
+    /// #
+    /// # Ok(()) }) }
+    /// ```
+    ///
+    /// # See Also
+    ///
+    /// This is a convenience function that coerces errors to false. If you want to
+    /// check errors, call [fs::metadata] and handle its Result. Then call
+    /// [fs::Metadata::is_dir] if it was Ok.
+    ///
+    /// [fs::metadata]: ../fs/fn.metadata.html
+    /// [fs::Metadata::is_dir]: ../fs/struct.Metadata.html#method.is_dir
+    pub async fn is_dir(&self) -> bool {
+        fs::metadata(self)
+            .await
+            .map(|m| m.file_type().is_dir())
+            .unwrap_or(false)
+    }
+
+    /// Returns `true` if the path exists on disk and is pointing at a regular file.
+    ///
+    /// This function will traverse symbolic links to query information about the
+    /// destination file. In case of broken symbolic links this will return `false`.
+    ///
+    /// If you cannot access the directory containing the file, e.g., because of a
+    /// permission error, this will return `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> std::io::Result<()> { async_std::task::block_on(async {
+    /// #
+    /// use async_std::path::Path;
+    /// assert_eq!(Path::new("./is_a_directory/").is_file().await, false);
+    /// assert_eq!(Path::new("a_file.txt").is_file().await, true);
+    /// #
+    /// # Ok(()) }) }
+    /// ```
+    ///
+    /// # See Also
+    ///
+    /// This is a convenience function that coerces errors to false. If you want to
+    /// check errors, call [fs::metadata] and handle its Result. Then call
+    /// [fs::Metadata::is_file] if it was Ok.
+    ///
+    /// [fs::metadata]: ../fs/fn.metadata.html
+    /// [fs::Metadata::is_file]: ../fs/struct.Metadata.html#method.is_file
+    pub async fn is_file(&self) -> bool {
+        fs::metadata(self)
+            .await
+            .map(|m| m.file_type().is_file())
+            .unwrap_or(false)
+    }
+
     /// Queries the file system to get information about a file, directory, etc.
     ///
     /// This function will traverse symbolic links to query information about the
@@ -235,6 +348,48 @@ impl Path {
         fs::metadata(self).await
     }
 
+    /// Determines whether `base` is a prefix of `self`, ignoring ASCII case.
+    ///
+    /// Only considers whole path components to match, comparing each [`Normal`]
+    /// component with an ASCII case-insensitive equality and every other
+    /// component (`RootDir`, `Prefix`, `CurDir`, `ParentDir`) exactly, just like
+    /// [`starts_with`].
+    ///
+    /// This is useful on filesystems that are case-insensitive by default, such
+    /// as NTFS, APFS, or FAT, where [`starts_with`] is too strict because it is
+    /// always case-sensitive regardless of platform.
+    ///
+    /// [`Normal`]: enum.Component.html#variant.Normal
+    /// [`starts_with`]: struct.Path.html#method.starts_with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use async_std::path::Path;
+    ///
+    /// let path = Path::new("/Etc/passwd");
+    ///
+    /// assert!(path.starts_with_ignore_case("/etc"));
+    /// ```
+    pub fn starts_with_ignore_case<P: AsRef<Path>>(&self, base: P) -> bool {
+        let base = base.as_ref();
+        let mut self_components = self.inner.components();
+        let base_components = base.inner.components();
+
+        for base_component in base_components {
+            match self_components.next() {
+                Some(self_component) => {
+                    if !components_eq_ignore_case(self_component, base_component) {
+                        return false;
+                    }
+                }
+                None => return false,
+            }
+        }
+
+        true
+    }
+
     /// Queries the metadata about a file without following symlinks.
     ///
     /// This is an alias to [`fs::symlink_metadata`].
@@ -258,6 +413,61 @@ impl Path {
         fs::symlink_metadata(self).await
     }
 
+    /// Returns a stream over the entries within a directory.
+    ///
+    /// The stream will yield instances of [`io::Result`]`<`[`fs::DirEntry`]`>`. New
+    /// errors may be encountered after a stream is initially constructed.
+    ///
+    /// This is an alias to [`fs::read_dir`].
+    ///
+    /// [`io::Result`]: ../io/type.Result.html
+    /// [`fs::DirEntry`]: ../fs/struct.DirEntry.html
+    /// [`fs::read_dir`]: ../fs/fn.read_dir.html
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> std::io::Result<()> { async_std::task::block_on(async {
+    /// #
+    /// use async_std::path::Path;
+    /// use async_std::prelude::*;
+    ///
+    /// let path = Path::new("/laputa");
+    /// let mut dir = path.read_dir().await?;
+    /// while let Some(entry) = dir.next().await {
+    ///     if let Ok(entry) = entry {
+    ///         println!("{:?}", entry.file_name());
+    ///     }
+    /// }
+    /// #
+    /// # Ok(()) }) }
+    /// ```
+    pub async fn read_dir(&self) -> io::Result<fs::ReadDir> {
+        fs::read_dir(self).await
+    }
+
+    /// Reads a symbolic link, returning the file that the link points to.
+    ///
+    /// This is an alias to [`fs::read_link`].
+    ///
+    /// [`fs::read_link`]: ../fs/fn.read_link.html
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> std::io::Result<()> { async_std::task::block_on(async {
+    /// #
+    /// use async_std::path::Path;
+    ///
+    /// let path = Path::new("/laputa/sky_castle.rs");
+    /// let path_link = path.read_link().await?;
+    /// #
+    /// # Ok(()) }) }
+    /// ```
+    pub async fn read_link(&self) -> io::Result<PathBuf> {
+        fs::read_link(self).await
+    }
+
     /// Directly wraps a string slice as a `Path` slice.
     ///
     /// This is a cost-free conversion.
@@ -284,6 +494,66 @@ impl Path {
         unsafe { &*(std::path::Path::new(s) as *const std::path::Path as *const Path) }
     }
 
+    /// Creates an owned [`PathBuf`] with path `.`/`..` components lexically
+    /// collapsed, without touching the filesystem.
+    ///
+    /// Unlike [`canonicalize`], this does not resolve symlinks and performs no I/O:
+    /// it only inspects [`components`]. `RootDir` and `Prefix` components are kept
+    /// as-is, `CurDir` (`.`) components are dropped, and each `ParentDir` (`..`)
+    /// pops the previously pushed `Normal` component, if any. A `..` that has
+    /// nothing to pop (because there is no preceding normal component, or because
+    /// the preceding component is itself a `..`) is kept in the result, and `..`
+    /// is never popped across the root. If every component cancels out, the
+    /// result is `"."` rather than an empty path.
+    ///
+    /// Because this is purely lexical, it is not equivalent to [`canonicalize`]:
+    /// `a/b/../c` normalizes to `a/c` only under the assumption that `b` is not a
+    /// symbolic link.
+    ///
+    /// [`PathBuf`]: struct.PathBuf.html
+    /// [`canonicalize`]: struct.Path.html#method.canonicalize
+    /// [`components`]: struct.Path.html#method.components
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use async_std::path::{Path, PathBuf};
+    ///
+    /// assert_eq!(Path::new("a/b/../c").normalize(), PathBuf::from("a/c"));
+    /// assert_eq!(Path::new("a/./b").normalize(), PathBuf::from("a/b"));
+    /// assert_eq!(Path::new("../a").normalize(), PathBuf::from("../a"));
+    /// assert_eq!(Path::new("foo/..").normalize(), PathBuf::from("."));
+    /// ```
+    pub fn normalize(&self) -> PathBuf {
+        use std::path::Component::*;
+
+        let mut result = std::path::PathBuf::new();
+
+        for component in self.inner.components() {
+            match component {
+                CurDir => {}
+                ParentDir => match result.components().next_back() {
+                    Some(std::path::Component::Normal(_)) => {
+                        result.pop();
+                    }
+                    Some(std::path::Component::ParentDir) | None => {
+                        result.push("..");
+                    }
+                    Some(std::path::Component::RootDir)
+                    | Some(std::path::Component::Prefix(_)) => {}
+                    Some(std::path::Component::CurDir) => unreachable!(),
+                },
+                component => result.push(component.as_os_str()),
+            }
+        }
+
+        if result.as_os_str().is_empty() {
+            result.push(".");
+        }
+
+        PathBuf::from(result)
+    }
+
     /// Converts a `Path` to an owned [`PathBuf`].
     ///
     /// [`PathBuf`]: struct.PathBuf.html
@@ -299,6 +569,129 @@ impl Path {
     pub fn to_path_buf(&self) -> PathBuf {
         PathBuf::from(self.inner.to_path_buf())
     }
+
+    /// Converts a `Path` to an owned, lossily-converted `String`.
+    ///
+    /// This is equivalent to `self.to_string_lossy().into_owned()`, provided
+    /// as a convenience for callers who want an owned `String` straight away
+    /// rather than the borrowing [`Cow`] returned by [`to_string_lossy`].
+    ///
+    /// Any non-Unicode sequences are replaced with
+    /// `U+FFFD REPLACEMENT CHARACTER`.
+    ///
+    /// [`Cow`]: https://doc.rust-lang.org/std/borrow/enum.Cow.html
+    /// [`to_string_lossy`]: https://doc.rust-lang.org/std/path/struct.Path.html#method.to_string_lossy
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use async_std::path::Path;
+    ///
+    /// let path = Path::new("foo.txt");
+    ///
+    /// assert_eq!(path.to_string_lossy_owned(), "foo.txt".to_string());
+    /// ```
+    pub fn to_string_lossy_owned(&self) -> String {
+        self.inner.to_string_lossy().into_owned()
+    }
+
+    /// Returns an iterator over the lossy UTF-8 rendering of this path as a
+    /// sequence of valid UTF-8 fragments ([`Utf8LossyChunk`]), each optionally
+    /// followed by a `U+FFFD` replacement marker.
+    ///
+    /// Unlike [`to_string_lossy`] and [`to_string_lossy_owned`], this never
+    /// materializes the full lossily-converted string on platforms that
+    /// expose a path's raw encoding; it only ever borrows from `self`. This
+    /// matters when e.g. logging directory entries whose names may contain
+    /// invalid encoding, one fragment at a time, into a sink such as an
+    /// [`async_std::io::Write`] writer — see [`write_lossy_to`] for exactly
+    /// that.
+    ///
+    /// Concatenating every yielded chunk's [`valid`] fragment, with
+    /// `"\u{FFFD}"` inserted wherever [`broken`] is `true`, reproduces exactly
+    /// what [`to_string_lossy`] would produce.
+    ///
+    /// On Unix, the path's raw bytes are split at each invalid UTF-8
+    /// sequence, so arbitrarily many chunks can be yielded without
+    /// allocating. On other platforms, where there is no portable way to
+    /// inspect a path's raw encoding, this falls back to a single chunk
+    /// holding the whole [`to_string_lossy`] rendering.
+    ///
+    /// [`Utf8LossyChunk`]: struct.Utf8LossyChunk.html
+    /// [`valid`]: struct.Utf8LossyChunk.html#structfield.valid
+    /// [`broken`]: struct.Utf8LossyChunk.html#structfield.broken
+    /// [`to_string_lossy`]: https://doc.rust-lang.org/std/path/struct.Path.html#method.to_string_lossy
+    /// [`to_string_lossy_owned`]: struct.Path.html#method.to_string_lossy_owned
+    /// [`write_lossy_to`]: struct.Path.html#method.write_lossy_to
+    /// [`async_std::io::Write`]: ../io/trait.Write.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use async_std::path::Path;
+    ///
+    /// let path = Path::new("/tmp/foo.txt");
+    /// let mut rendered = String::new();
+    /// for chunk in path.utf8_chunks() {
+    ///     rendered.push_str(&chunk.valid);
+    ///     if chunk.broken {
+    ///         rendered.push('\u{FFFD}');
+    ///     }
+    /// }
+    /// assert_eq!(rendered, path.to_string_lossy_owned());
+    /// ```
+    pub fn utf8_chunks(&self) -> Utf8LossyChunks<'_> {
+        #[cfg(unix)]
+        {
+            use std::os::unix::ffi::OsStrExt;
+
+            Utf8LossyChunks::from_bytes(self.inner.as_os_str().as_bytes())
+        }
+
+        #[cfg(not(unix))]
+        {
+            Utf8LossyChunks::from_lossy(self.inner.to_string_lossy())
+        }
+    }
+
+    /// Writes the lossy UTF-8 rendering of this path to `writer`, one
+    /// [`utf8_chunks`] fragment at a time.
+    ///
+    /// On Unix, the full string is never materialized in memory. On other
+    /// platforms, [`utf8_chunks`] yields the whole [`to_string_lossy`]
+    /// rendering as a single chunk, which is written in one piece.
+    ///
+    /// [`to_string_lossy`]: https://doc.rust-lang.org/std/path/struct.Path.html#method.to_string_lossy
+    /// [`utf8_chunks`]: struct.Path.html#method.utf8_chunks
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> std::io::Result<()> { async_std::task::block_on(async {
+    /// #
+    /// use async_std::path::Path;
+    /// use async_std::io;
+    ///
+    /// let path = Path::new("/tmp/foo.txt");
+    /// let mut stdout = io::stdout();
+    /// path.write_lossy_to(&mut stdout).await?;
+    /// #
+    /// # Ok(()) }) }
+    /// ```
+    pub async fn write_lossy_to<W>(&self, writer: &mut W) -> io::Result<()>
+    where
+        W: io::Write + Unpin + ?Sized,
+    {
+        use crate::io::WriteExt;
+
+        for chunk in self.utf8_chunks() {
+            writer.write_all(chunk.valid.as_bytes()).await?;
+            if chunk.broken {
+                writer.write_all("\u{FFFD}".as_bytes()).await?;
+            }
+        }
+        Ok(())
+    }
 }
 
 impl<'a> From<&'a std::path::Path> for &'a Path {
@@ -336,3 +729,41 @@ impl AsRef<Path> for str {
         Path::new(self)
     }
 }
+
+/// Compares two path components for equality, folding ASCII case on [`Normal`]
+/// components and comparing every other component kind exactly.
+///
+/// [`Normal`]: enum.Component.html#variant.Normal
+fn components_eq_ignore_case(
+    a: std::path::Component<'_>,
+    b: std::path::Component<'_>,
+) -> bool {
+    use std::path::Component::*;
+
+    match (a, b) {
+        (Normal(a), Normal(b)) => os_str_eq_ignore_ascii_case(a, b),
+        (a, b) => a == b,
+    }
+}
+
+/// Compares two [`OsStr`]s for ASCII case-insensitive equality over their raw
+/// encoding, rather than through a lossy UTF-8 conversion (which would map
+/// distinct invalid byte sequences onto the same `U+FFFD` and falsely report
+/// them as equal).
+#[cfg(unix)]
+fn os_str_eq_ignore_ascii_case(a: &OsStr, b: &OsStr) -> bool {
+    use std::os::unix::ffi::OsStrExt;
+
+    a.as_bytes().eq_ignore_ascii_case(b.as_bytes())
+}
+
+/// Compares two [`OsStr`]s for ASCII case-insensitive equality. Falls back to
+/// exact equality whenever either side isn't valid Unicode, since we have no
+/// portable way to fold ASCII case in the raw encoding on this platform.
+#[cfg(not(unix))]
+fn os_str_eq_ignore_ascii_case(a: &OsStr, b: &OsStr) -> bool {
+    match (a.to_str(), b.to_str()) {
+        (Some(a), Some(b)) => a.eq_ignore_ascii_case(b),
+        _ => a == b,
+    }
+}
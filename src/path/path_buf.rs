@@ -0,0 +1,47 @@
+use std::collections::TryReserveError;
+
+use crate::path::PathBuf;
+
+impl PathBuf {
+    /// Tries to reserve capacity for at least `additional` more bytes to be
+    /// inserted in the given `PathBuf`. The collection may reserve more space
+    /// to avoid frequent reallocations. After calling `try_reserve`, capacity
+    /// will be greater than or equal to the current length plus `additional`
+    /// if it returns `Ok(())`. Does nothing if capacity is already sufficient. This
+    /// method preserves the contents even if an error occurs.
+    ///
+    /// This is an alias to [`OsString::try_reserve`].
+    ///
+    /// [`OsString::try_reserve`]: https://doc.rust-lang.org/std/ffi/struct.OsString.html#method.try_reserve
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an
+    /// error is returned.
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.inner.try_reserve(additional)
+    }
+
+    /// Tries to reserve the minimum capacity for exactly `additional` more
+    /// bytes to be inserted in the given `PathBuf`. After calling
+    /// `try_reserve_exact`, capacity will be greater than or equal to the
+    /// current length plus `additional` if it returns `Ok(())`. Does nothing if
+    /// the capacity is already sufficient.
+    ///
+    /// Note that the allocator may give the collection more space than it
+    /// requests. Therefore, capacity can not be relied upon to be precisely
+    /// minimal. Prefer [`try_reserve`] if future insertions are expected.
+    ///
+    /// This is an alias to [`OsString::try_reserve_exact`].
+    ///
+    /// [`try_reserve`]: struct.PathBuf.html#method.try_reserve
+    /// [`OsString::try_reserve_exact`]: https://doc.rust-lang.org/std/ffi/struct.OsString.html#method.try_reserve_exact
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an
+    /// error is returned.
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.inner.try_reserve_exact(additional)
+    }
+}